@@ -0,0 +1,114 @@
+use std::str::FromStr;
+
+use chrono::prelude::*;
+use failure::Error;
+
+/// A possibly half-bounded range of dates, parsed from `START/END` where
+/// either side may be omitted: `2024-01-01/` scrapes forward from that date
+/// through today, `/2024-03-01` scrapes everything up to that date, and
+/// `2024-01-01/2024-03-01` bounds both ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DateRange {
+    pub from: Option<Date<Local>>,
+    pub to: Option<Date<Local>>,
+}
+
+impl DateRange {
+    /// Whether `date` falls within this range. An unset `from` passes
+    /// everything on or before `to`; an unset `to` passes everything on or
+    /// after `from`.
+    pub fn includes(&self, date: Date<Local>) -> bool {
+        self.from.map_or(true, |from| date >= from) && self.to.map_or(true, |to| date <= to)
+    }
+}
+
+impl FromStr for DateRange {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let slash = s
+            .find('/')
+            .ok_or_else(|| format_err!("Expected START/END, got '{}'", s))?;
+        let (from_str, to_str) = (&s[..slash], &s[slash + 1..]);
+        Ok(DateRange {
+            from: parse_bound(from_str)?,
+            to: parse_bound(to_str)?,
+        })
+    }
+}
+
+fn parse_bound(s: &str) -> Result<Option<Date<Local>>, Error> {
+    if s.is_empty() {
+        Ok(None)
+    } else {
+        let naive: NaiveDate = s.parse().map_err(|err| format_err!("{}", err))?;
+        Local
+            .from_local_date(&naive)
+            .single()
+            .map(Some)
+            .ok_or_else(|| format_err!("Ambiguous or invalid local date '{}'", s))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_a_fully_bounded_range() {
+        let range: DateRange = "2024-01-01/2024-03-01".parse().unwrap();
+        assert_eq!(range.from, Some(Local.ymd(2024, 1, 1)));
+        assert_eq!(range.to, Some(Local.ymd(2024, 3, 1)));
+    }
+
+    #[test]
+    fn parses_an_open_ended_from() {
+        let range: DateRange = "2024-01-01/".parse().unwrap();
+        assert_eq!(range.from, Some(Local.ymd(2024, 1, 1)));
+        assert_eq!(range.to, None);
+    }
+
+    #[test]
+    fn parses_an_open_ended_to() {
+        let range: DateRange = "/2024-03-01".parse().unwrap();
+        assert_eq!(range.from, None);
+        assert_eq!(range.to, Some(Local.ymd(2024, 3, 1)));
+    }
+
+    #[test]
+    fn rejects_input_without_a_slash() {
+        assert!("2024-01-01".parse::<DateRange>().is_err());
+    }
+
+    #[test]
+    fn includes_respects_each_bound_independently() {
+        let from_only = DateRange {
+            from: Some(Local.ymd(2024, 1, 1)),
+            to: None,
+        };
+        assert!(!from_only.includes(Local.ymd(2023, 12, 31)));
+        assert!(from_only.includes(Local.ymd(2024, 1, 1)));
+        assert!(from_only.includes(Local.ymd(2030, 1, 1)));
+
+        let to_only = DateRange {
+            from: None,
+            to: Some(Local.ymd(2024, 3, 1)),
+        };
+        assert!(to_only.includes(Local.ymd(2000, 1, 1)));
+        assert!(to_only.includes(Local.ymd(2024, 3, 1)));
+        assert!(!to_only.includes(Local.ymd(2024, 3, 2)));
+    }
+
+    #[test]
+    fn combining_two_partial_selectors_takes_the_tighter_bound() {
+        let lower: DateRange = "2024-01-01/".parse().unwrap();
+        let upper: DateRange = "/2024-03-01".parse().unwrap();
+        let combined = DateRange {
+            from: lower.from,
+            to: upper.to,
+        };
+        assert!(combined.includes(Local.ymd(2024, 2, 1)));
+        assert!(!combined.includes(Local.ymd(2023, 6, 1)));
+        assert!(!combined.includes(Local.ymd(2024, 6, 1)));
+    }
+}
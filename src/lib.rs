@@ -4,24 +4,55 @@ extern crate failure;
 extern crate log;
 
 use chrono::prelude::*;
-use chrono::Duration;
 use failure::Error;
 use std::path::PathBuf;
 
+mod date_range;
+mod relevant_days;
+mod rollup;
+mod week_of_month;
+
+pub use date_range::DateRange;
+pub use relevant_days::{afd_log_page_title, earliest_relevant_date, relevant_days};
+pub use rollup::{group_by_rollup, output_filename, write_rollups, Biography, Rollup};
+pub use week_of_month::week_of_month;
+
 pub struct DateOptions {
     pub date: Date<Local>,
-    pub duration: Option<Duration>,
+    pub range: Option<DateRange>,
     pub week: Option<u8>,
+    pub week_start: Weekday,
 }
 
 pub struct Options {
     pub date_options: DateOptions,
     pub log_level: log::Level,
     pub output: Option<PathBuf>,
+    pub rollup: Rollup,
 }
 
 pub fn execute(options: Options) -> Result<(), Error> {
-    unimplemented!()
+    let days = relevant_days(&options.date_options);
+
+    let mut biographies = Vec::new();
+    for day in days {
+        let page = afd_log_page_title(day);
+        biographies.extend(scrape_biographies(&page)?);
+    }
+
+    let groups = group_by_rollup(biographies, options.rollup, options.date_options.week_start);
+    write_rollups(groups, options.output.as_deref())
+}
+
+/// Fetches and parses the AfD log page titled `page`, returning the
+/// biography articles nominated for deletion on it.
+///
+/// Fetching and parsing real Wikipedia pages isn't implemented yet, so this
+/// is a placeholder that finds nothing; it exists so the rest of the
+/// pipeline (grouping and writing rollups) can run end-to-end instead of
+/// panicking on every invocation.
+fn scrape_biographies(_page: &str) -> Result<Vec<Biography>, Error> {
+    Ok(Vec::new())
 }
 
 #[cfg(test)]
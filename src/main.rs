@@ -13,7 +13,7 @@ use chrono::prelude::*;
 use clap::Arg;
 use failure::Error;
 use std::path::PathBuf;
-use wikip::{execute, DateOptions, Options};
+use wikip::{earliest_relevant_date, execute, DateOptions, DateRange, Options, Rollup};
 
 fn main() {
     let options = parse_args().unwrap();
@@ -42,7 +42,10 @@ fn parse_args<'a>() -> Result<Options, Error> {
                 .takes_value(true)
                 .help("An inclusive range of dates to scrape.")
                 .long_help(
-                    "An inclusive range of dates to scrape. The format is YYYY-MM-DD/YYYY-MM-DD",
+                    "An inclusive range of dates to scrape. The format is START/END, where \
+                     either side may be omitted: YYYY-MM-DD/ scrapes forward through today, \
+                     /YYYY-MM-DD scrapes everything up to that date, and \
+                     YYYY-MM-DD/YYYY-MM-DD bounds both ends.",
                 ),
         )
         .arg(
@@ -67,33 +70,60 @@ fn parse_args<'a>() -> Result<Options, Error> {
             )
         .arg(
             Arg::with_name("output")
-            .value_name("FILENAME")
+            .value_name("DIR")
             .short("o")
             .long("output")
                 .takes_value(true)
             .required(false)
-            .help("The output file.")
-            .long_help("THe output file. It defaults to 'afd-bios-DATE.csv'.")
+            .help("The directory to write the output file(s) to.")
+            .long_help(
+                "The directory to write the output file(s) to. Defaults to the current \
+                 directory. File names are generated from --rollup, e.g. \
+                 'afd-bios-DATE.csv' for the default day rollup.",
+            )
+            )
+        .arg(
+            Arg::with_name("week_start")
+            .value_name("WEEKDAY")
+                .long("week-start")
+                .takes_value(true)
+            .default_value("Mon")
+            .possible_values(&["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"])
+            .help("The day a week starts on.")
+            .long_help(
+                "The day a week starts on. Used both by --week to find week boundaries and by \
+                 --rollup week to group output files. Defaults to Mon.",
+            )
+            )
+        .arg(
+            Arg::with_name("rollup")
+            .value_name("ROLLUP")
+                .long("rollup")
+                .takes_value(true)
+            .default_value("day")
+            .possible_values(&["day", "week", "month"])
+            .help("The granularity of the output files.")
+            .long_help(
+                "The granularity of the output files. 'day' writes one file per day \
+                 (the default), 'week' groups biographies into one file per week, and \
+                 'month' groups them into one file per month.",
+            )
             )
         .get_matches();
 
+    let date_range: Option<DateRange> = matches
+        .value_of("date_range")
+        .map(|dr| dr.parse())
+        .transpose()
+        .map_err(|err| format_err!("Error reading DATE_RANGE: {}", &err))?;
     let start_date: Date<Local> = matches
         .value_of("date")
-        .or_else(|| {
-            matches
-                .value_of("date_range")
-                .and_then(|dr| dr.split('/').next())
-        })
         .map(|date_str| date_str.parse().map(|dt: DateTime<Local>| dt.date()))
         .transpose()
         .map_err(|err| format_err!("Error reading DATE: {}", &err))?
+        .or_else(|| date_range.and_then(|range| range.from))
+        .or_else(|| date_range.map(|_| earliest_relevant_date()))
         .unwrap_or_else(|| Local::now().date());
-    let end_date: Option<Date<Local>> = matches
-        .value_of("date_range")
-        .and_then(|dr| dr.split('/').nth(1))
-        .map(|date_str| date_str.parse().map(|dt: DateTime<Local>| dt.date()))
-        .transpose()
-        .map_err(|err| format_err!("Error reading DATE_RANGE: {}", &err))?;
     let week: Option<u8> = matches
         .value_of("date")
         .or_else(|| matches.value_of("date_range"))
@@ -101,6 +131,11 @@ fn parse_args<'a>() -> Result<Options, Error> {
         .map(|w| w.parse())
         .transpose()
         .map_err(|err| format_err!("Error reading WEEK: {}", &err))?;
+    let week_start: Weekday = matches
+        .value_of("week_start")
+        .unwrap_or("Mon")
+        .parse()
+        .map_err(|err| format_err!("Error reading WEEKDAY: {}", &err))?;
     let log_level: log::Level = matches
         .value_of("level")
         .unwrap_or("Warn")
@@ -111,14 +146,22 @@ fn parse_args<'a>() -> Result<Options, Error> {
         .map(|o| o.parse())
         .transpose()
         .map_err(|err| format_err!("Error reading OUTPUT: {}", &err))?;
+    let rollup = match matches.value_of("rollup").unwrap_or("day") {
+        "day" => Rollup::Day,
+        "week" => Rollup::Week,
+        "month" => Rollup::Month,
+        other => return Err(format_err!("Error reading ROLLUP: unknown value '{}'", other)),
+    };
 
     Ok(Options {
         date_options: DateOptions {
             date: start_date,
-            duration: end_date.map(|ed| ed - start_date),
+            range: date_range,
             week,
+            week_start,
         },
         log_level,
         output,
+        rollup,
     })
 }
@@ -0,0 +1,163 @@
+use chrono::prelude::*;
+
+use crate::week_of_month::week_of_month;
+use crate::{DateOptions, DateRange};
+
+/// The minimum length, in days, for a month's leading week to count as a
+/// week of its own rather than being folded into week 0. See
+/// `week_of_month` for the full rule.
+const MIN_WEEK_DAYS: u8 = 7;
+
+/// Enumerates the days `execute` should scrape, in order.
+///
+/// The walk starts at `date_options.date` and runs through
+/// `date_options.range`'s `to` bound, consulting the range's `includes` on
+/// each day and filtering down to `date_options.week` when one is set. An
+/// open-ended `to` runs through today, and with no range at all only
+/// `date_options.date` itself is considered; callers with an
+/// open-ended `from` are expected to have already picked a real starting
+/// `date_options.date` (see `earliest_relevant_date`), since this function
+/// has no way to walk backward from an unknown start.
+pub fn relevant_days(date_options: &DateOptions) -> Vec<Date<Local>> {
+    let start = date_options.date;
+    let end = match &date_options.range {
+        Some(range) => range.to.unwrap_or_else(|| Local::now().date()),
+        None => start,
+    };
+
+    let mut days = Vec::new();
+    let mut day = start;
+    while day <= end {
+        let in_range = date_options
+            .range
+            .map(|range| range.includes(day))
+            .unwrap_or(true);
+        let in_week = date_options
+            .week
+            .map(|week| {
+                week_of_month(day, date_options.week_start, MIN_WEEK_DAYS) == u32::from(week)
+            })
+            .unwrap_or(true);
+        if in_range && in_week {
+            days.push(day);
+        }
+        day = day.succ();
+    }
+    days
+}
+
+/// A practical lower bound for an open-ended "from" date range: the day the
+/// English Wikipedia was founded, which safely predates any AfD log page.
+/// `DateOptions.date` should be set to this when a `--date-range` gives a
+/// `to` bound but no `from`, so `relevant_days` has a concrete start to walk
+/// forward from.
+pub fn earliest_relevant_date() -> Date<Local> {
+    Local.ymd(2001, 1, 15)
+}
+
+/// The title of the Wikipedia page logging a day's AfD discussions, e.g.
+/// "Articles for deletion/Log/2024 January 15".
+pub fn afd_log_page_title(date: Date<Local>) -> String {
+    format!(
+        "Articles for deletion/Log/{} {}",
+        date.format("%Y %B"),
+        date.day()
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn single_day_with_no_range() {
+        let date_options = DateOptions {
+            date: Local.ymd(2024, 2, 5),
+            range: None,
+            week: None,
+            week_start: Weekday::Mon,
+        };
+        assert_eq!(relevant_days(&date_options), vec![Local.ymd(2024, 2, 5)]);
+    }
+
+    #[test]
+    fn range_is_inclusive_of_both_ends() {
+        let date_options = DateOptions {
+            date: Local.ymd(2024, 2, 5),
+            range: Some(DateRange {
+                from: Some(Local.ymd(2024, 2, 5)),
+                to: Some(Local.ymd(2024, 2, 7)),
+            }),
+            week: None,
+            week_start: Weekday::Mon,
+        };
+        assert_eq!(
+            relevant_days(&date_options),
+            vec![
+                Local.ymd(2024, 2, 5),
+                Local.ymd(2024, 2, 6),
+                Local.ymd(2024, 2, 7),
+            ]
+        );
+    }
+
+    #[test]
+    fn open_ended_to_runs_through_today() {
+        let today = Local::now().date();
+        let start = today - chrono::Duration::days(2);
+        let date_options = DateOptions {
+            date: start,
+            range: Some(DateRange {
+                from: Some(start),
+                to: None,
+            }),
+            week: None,
+            week_start: Weekday::Mon,
+        };
+        assert_eq!(relevant_days(&date_options).last(), Some(&today));
+    }
+
+    #[test]
+    fn walk_stops_at_the_ranges_to_bound() {
+        let date_options = DateOptions {
+            date: Local.ymd(2024, 2, 5),
+            range: Some(DateRange {
+                from: Some(Local.ymd(2024, 2, 5)),
+                to: Some(Local.ymd(2024, 2, 6)),
+            }),
+            week: None,
+            week_start: Weekday::Mon,
+        };
+        assert_eq!(
+            relevant_days(&date_options),
+            vec![Local.ymd(2024, 2, 5), Local.ymd(2024, 2, 6)]
+        );
+    }
+
+    #[test]
+    fn week_filter_narrows_the_range() {
+        let date_options = DateOptions {
+            date: Local.ymd(2024, 2, 1),
+            range: Some(DateRange {
+                from: Some(Local.ymd(2024, 2, 1)),
+                to: Some(Local.ymd(2024, 2, 14)),
+            }),
+            week: Some(1),
+            week_start: Weekday::Mon,
+        };
+        // 2024-02-01 is a Thursday, so week 1 (Mon-based, min_week_days=7)
+        // is the first full week: Feb 5 - Feb 11.
+        assert_eq!(
+            relevant_days(&date_options),
+            (5..=11).map(|d| Local.ymd(2024, 2, d)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn log_page_title_matches_wikipedia_naming() {
+        assert_eq!(
+            afd_log_page_title(Local.ymd(2024, 1, 5)),
+            "Articles for deletion/Log/2024 January 5"
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use chrono::prelude::*;
+use failure::Error;
+
+use crate::week_of_month::week_start_of;
+
+/// A biography surfaced by an AfD discussion, ready to be written out as a
+/// row of `afd-bios-*.csv`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Biography {
+    pub name: String,
+    pub date: Date<Local>,
+}
+
+/// How finely to split scraped biographies across output files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rollup {
+    Day,
+    Week,
+    Month,
+}
+
+/// The output filename a biography scraped on `date` belongs in, under the
+/// given `rollup` granularity. Weekly rollups bucket by the week starting
+/// on `week_start`.
+pub fn output_filename(rollup: Rollup, date: Date<Local>, week_start: Weekday) -> String {
+    match rollup {
+        Rollup::Day => format!("afd-bios-{}.csv", date.format("%Y-%m-%d")),
+        Rollup::Week => format!(
+            "afd-bios-week-of-{}.csv",
+            week_start_of(date, week_start).format("%Y-%m-%d")
+        ),
+        Rollup::Month => format!("afd-bios-{}.csv", date.format("%Y-%m")),
+    }
+}
+
+/// Groups `biographies` by the output file they belong in under `rollup`.
+pub fn group_by_rollup(
+    biographies: Vec<Biography>,
+    rollup: Rollup,
+    week_start: Weekday,
+) -> HashMap<String, Vec<Biography>> {
+    let mut groups: HashMap<String, Vec<Biography>> = HashMap::new();
+    for bio in biographies {
+        let filename = output_filename(rollup, bio.date, week_start);
+        groups.entry(filename).or_insert_with(Vec::new).push(bio);
+    }
+    groups
+}
+
+/// Writes each rollup group to its own CSV file under `output_dir`
+/// (the current directory when unset), creating files on demand.
+pub fn write_rollups(
+    groups: HashMap<String, Vec<Biography>>,
+    output_dir: Option<&Path>,
+) -> Result<(), Error> {
+    for (filename, biographies) in groups {
+        let path: PathBuf = match output_dir {
+            Some(dir) => dir.join(filename),
+            None => PathBuf::from(filename),
+        };
+        let mut file = File::create(&path)?;
+        writeln!(file, "name,date")?;
+        for bio in biographies {
+            writeln!(file, "{},{}", bio.name, bio.date.format("%Y-%m-%d"))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn day_rollup_gives_one_file_per_day() {
+        assert_eq!(
+            output_filename(Rollup::Day, Local.ymd(2024, 2, 5), Weekday::Mon),
+            "afd-bios-2024-02-05.csv"
+        );
+    }
+
+    #[test]
+    fn week_rollup_names_by_week_start() {
+        // 2024-02-05 is a Monday, so it is its own week start.
+        assert_eq!(
+            output_filename(Rollup::Week, Local.ymd(2024, 2, 7), Weekday::Mon),
+            "afd-bios-week-of-2024-02-05.csv"
+        );
+    }
+
+    #[test]
+    fn week_rollup_honors_a_sunday_week_start() {
+        // 2024-02-04 is a Sunday, so with Sun as the week start it begins its own week.
+        assert_eq!(
+            output_filename(Rollup::Week, Local.ymd(2024, 2, 7), Weekday::Sun),
+            "afd-bios-week-of-2024-02-04.csv"
+        );
+    }
+
+    #[test]
+    fn month_rollup_names_by_year_and_month() {
+        assert_eq!(
+            output_filename(Rollup::Month, Local.ymd(2024, 2, 7), Weekday::Mon),
+            "afd-bios-2024-02.csv"
+        );
+    }
+
+    #[test]
+    fn biographies_are_grouped_by_their_rollup_file() {
+        let biographies = vec![
+            Biography {
+                name: "Alice".to_string(),
+                date: Local.ymd(2024, 2, 5),
+            },
+            Biography {
+                name: "Bob".to_string(),
+                date: Local.ymd(2024, 2, 6),
+            },
+            Biography {
+                name: "Carol".to_string(),
+                date: Local.ymd(2024, 3, 1),
+            },
+        ];
+
+        let groups = group_by_rollup(biographies, Rollup::Month, Weekday::Mon);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups["afd-bios-2024-02.csv"].len(), 2);
+        assert_eq!(groups["afd-bios-2024-03.csv"].len(), 1);
+    }
+}
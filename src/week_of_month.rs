@@ -0,0 +1,103 @@
+use chrono::prelude::*;
+
+/// Computes which week of the month `date` falls in.
+///
+/// Weeks start on `first_weekday`. The leading, possibly partial week (the
+/// run of days from the 1st of the month up to the first `first_weekday`)
+/// counts as week 1 only if it is at least `min_week_days` days long;
+/// otherwise it is too short to stand on its own and is reported as week 0,
+/// carried over from the prior month. Every full week after that increments
+/// by one. Setting `min_week_days` to 1 means the leading week always
+/// counts as week 1; a higher threshold (e.g. 7) implements the "first full
+/// week of the month" semantics instead.
+pub fn week_of_month(date: Date<Local>, first_weekday: Weekday, min_week_days: u8) -> u32 {
+    let weekday_of_first = date.with_day(1).unwrap().weekday();
+    let first_week_len = 7 - days_from(weekday_of_first, first_weekday);
+    let day = date.day();
+
+    if day <= first_week_len {
+        if first_week_len >= u32::from(min_week_days) {
+            1
+        } else {
+            0
+        }
+    } else {
+        let base = if first_week_len >= u32::from(min_week_days) {
+            2
+        } else {
+            1
+        };
+        (day - first_week_len - 1) / 7 + base
+    }
+}
+
+/// The number of days `weekday` falls after `first_weekday`, wrapping within
+/// a 7-day week (e.g. `days_from(Tue, Mon) == 1`, `days_from(Mon, Tue) == 6`).
+pub(crate) fn days_from(weekday: Weekday, first_weekday: Weekday) -> u32 {
+    (weekday.num_days_from_monday() + 7 - first_weekday.num_days_from_monday()) % 7
+}
+
+/// The start of the week containing `date`, given weeks begin on
+/// `first_weekday`.
+pub fn week_start_of(date: Date<Local>, first_weekday: Weekday) -> Date<Local> {
+    date - chrono::Duration::days(i64::from(days_from(date.weekday(), first_weekday)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn full_leading_week_is_week_one() {
+        // 2024-01-01 is a Monday, so the Mon-based leading week is a full 7 days.
+        let date = Local.ymd(2024, 1, 1);
+        assert_eq!(week_of_month(date, Weekday::Mon, 7), 1);
+    }
+
+    #[test]
+    fn short_leading_week_falls_back_to_week_zero() {
+        // 2024-02-01 is a Thursday, so the Mon-based leading week is only 4 days.
+        let date = Local.ymd(2024, 2, 1);
+        assert_eq!(week_of_month(date, Weekday::Mon, 7), 0);
+    }
+
+    #[test]
+    fn low_threshold_always_counts_the_leading_week() {
+        let date = Local.ymd(2024, 2, 1);
+        assert_eq!(week_of_month(date, Weekday::Mon, 1), 1);
+    }
+
+    #[test]
+    fn days_after_the_leading_week_increment_correctly() {
+        let date = Local.ymd(2024, 2, 8);
+        assert_eq!(week_of_month(date, Weekday::Mon, 7), 1);
+        assert_eq!(week_of_month(date, Weekday::Mon, 1), 2);
+    }
+
+    #[test]
+    fn sunday_first_weekday_shifts_the_leading_week() {
+        // 2024-02-01 is a Thursday; with Sun as first_weekday the leading
+        // week runs Thu-Sat, 3 days long.
+        let date = Local.ymd(2024, 2, 1);
+        assert_eq!(week_of_month(date, Weekday::Sun, 7), 0);
+        assert_eq!(week_of_month(date, Weekday::Sun, 3), 1);
+    }
+
+    #[test]
+    fn week_start_of_finds_the_monday_for_mon_based_weeks() {
+        // 2024-02-07 is a Wednesday.
+        assert_eq!(
+            week_start_of(Local.ymd(2024, 2, 7), Weekday::Mon),
+            Local.ymd(2024, 2, 5)
+        );
+    }
+
+    #[test]
+    fn week_start_of_finds_the_sunday_for_sun_based_weeks() {
+        // 2024-02-07 is a Wednesday; the preceding Sunday is 2024-02-04.
+        assert_eq!(
+            week_start_of(Local.ymd(2024, 2, 7), Weekday::Sun),
+            Local.ymd(2024, 2, 4)
+        );
+    }
+}